@@ -59,36 +59,226 @@ struct EngineData {
     name: String,
     description: String,
     prefix: String,
+    key_specs: Vec<KeySpec>,
     profiles: Vec<HashMap<String, String>>,
 }
 
-/// Возвращает метаданные для engine по его id.
-fn get_engine_metadata(id: &str) -> Option<(String, String, String)> {
-    match id {
-        "bhyve" => Some((
-            "bhyve".to_string(),
-            "Native FreeBSD hypervisor".to_string(),
-            "b".to_string(),
-        )),
-        "xen" => Some((
-            "xen".to_string(),
-            "XEN type-1 hypervisor".to_string(),
-            "x".to_string(),
-        )),
-        "qemu" => Some((
-            "qemu".to_string(),
-            "QEMU hypervisor".to_string(),
-            "q".to_string(),
-        )),
-        _ => None,
+/// Метаданные одного engine, хранимые в реестре (встроенном или загруженном из файла).
+struct EngineDef {
+    name: String,
+    description: String,
+    prefix: String,
+    /// Дополнительные ключи CIX_PROFILES_DATA, применяемые только к этому engine.
+    extra_keys: Vec<KeySpec>,
+}
+
+/// Встроенный реестр engine, используемый, когда не задан ни `-e`, ни `CIX_ENGINES`.
+fn default_engine_registry() -> HashMap<String, EngineDef> {
+    let mut registry = HashMap::new();
+    registry.insert(
+        "bhyve".to_string(),
+        EngineDef {
+            name: "bhyve".to_string(),
+            description: "Native FreeBSD hypervisor".to_string(),
+            prefix: "b".to_string(),
+            extra_keys: Vec::new(),
+        },
+    );
+    registry.insert(
+        "xen".to_string(),
+        EngineDef {
+            name: "xen".to_string(),
+            description: "XEN type-1 hypervisor".to_string(),
+            prefix: "x".to_string(),
+            extra_keys: Vec::new(),
+        },
+    );
+    registry.insert(
+        "qemu".to_string(),
+        EngineDef {
+            name: "qemu".to_string(),
+            description: "QEMU hypervisor".to_string(),
+            prefix: "q".to_string(),
+            extra_keys: Vec::new(),
+        },
+    );
+    registry
+}
+
+/// Возвращает метаданные для engine по его id, ища в переданном реестре.
+fn get_engine_metadata<'a>(id: &str, registry: &'a HashMap<String, EngineDef>) -> Option<&'a EngineDef> {
+    registry.get(id)
+}
+
+/// Убирает обрамляющие кавычки (двойные или одинарные), если они есть.
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    if value.len() >= 2
+        && ((value.starts_with('"') && value.ends_with('"'))
+            || (value.starts_with('\'') && value.ends_with('\'')))
+    {
+        value[1..value.len() - 1].to_string()
+    } else {
+        value.to_string()
     }
 }
 
+/// Парсит TOML-массив строк вида `["imgsize:bytes", "vm_profile"]`.
+///
+/// На входе ожидается уже полностью собранный (возможно, из нескольких строк
+/// файла) текст массива — см. `collect_bracketed_value`.
+fn parse_string_array(value: &str) -> io::Result<Vec<String>> {
+    let value = value.trim();
+    let inner = match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        Some(inner) => inner,
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("extra_data must be an array, got: {}", value),
+            ))
+        }
+    };
+
+    Ok(inner
+        .split(',')
+        .map(|item| unquote(item))
+        .filter(|item| !item.is_empty())
+        .collect())
+}
+
+/// Считает баланс `[`/`]` в строке (без учёта вложенности кавычек).
+fn bracket_balance(s: &str) -> i32 {
+    s.chars().fold(0, |depth, c| match c {
+        '[' => depth + 1,
+        ']' => depth - 1,
+        _ => depth,
+    })
+}
+
+/// Дочитывает значение массива, начатое в `first_line`, до тех пор, пока
+/// скобки не сбалансируются — так TOML-массивы вроде `extra_data` могут быть
+/// записаны на нескольких строках. Возвращает ошибку, если файл кончается
+/// раньше, чем массив закрыт.
+fn collect_bracketed_value<I>(first_line: &str, lines: &mut I) -> io::Result<String>
+where
+    I: Iterator<Item = io::Result<String>>,
+{
+    let mut buf = first_line.to_string();
+    if !buf.trim_start().starts_with('[') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("extra_data must be an array, got: {}", buf.trim()),
+        ));
+    }
+
+    let mut depth = bracket_balance(&buf);
+    while depth > 0 {
+        match lines.next() {
+            Some(line) => {
+                let l = line?;
+                buf.push(' ');
+                buf.push_str(l.trim());
+                depth += bracket_balance(&l);
+            }
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "unterminated extra_data array: missing closing ']'",
+                ));
+            }
+        }
+    }
+
+    Ok(buf)
+}
+
+/// Загружает реестр engine из TOML-файла вида `[qemu]\nname = "..."\n...`.
+///
+/// Каждая секция описывает один engine: `id` (по умолчанию берётся из имени
+/// секции), `name`, `description`, `prefix` и опциональный список `extra_data`
+/// — дополнительные ключи CIX_PROFILES_DATA, применяемые только к этому engine.
+fn parse_engines_config<P: AsRef<Path>>(path: P) -> io::Result<HashMap<String, EngineDef>> {
+    let file = File::open(path)?;
+    let reader = io::BufReader::new(file);
+    let mut registry = HashMap::new();
+
+    let mut section_id: Option<String> = None;
+    let mut id_override: Option<String> = None;
+    let mut name = String::new();
+    let mut description = String::new();
+    let mut prefix = String::new();
+    let mut extra_keys: Vec<KeySpec> = Vec::new();
+
+    macro_rules! flush_section {
+        () => {
+            if let Some(section) = section_id.take() {
+                let id = id_override.take().unwrap_or(section);
+                registry.insert(
+                    id,
+                    EngineDef {
+                        name: std::mem::take(&mut name),
+                        description: std::mem::take(&mut description),
+                        prefix: std::mem::take(&mut prefix),
+                        extra_keys: std::mem::take(&mut extra_keys),
+                    },
+                );
+            }
+        };
+    }
+
+    let mut lines = reader.lines();
+    while let Some(line) = lines.next() {
+        let l = line?;
+        let trimmed = l.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            flush_section!();
+            // Сбрасываем локальные поля безусловно: без этого случайная строка
+            // `key = value`, стоящая перед первой секцией (или иначе не
+            // попавшая во flush_section!), тихо протекла бы в следующий engine.
+            id_override = None;
+            name.clear();
+            description.clear();
+            prefix.clear();
+            extra_keys.clear();
+            section_id = Some(trimmed[1..trimmed.len() - 1].trim().to_string());
+            continue;
+        }
+
+        if let Some(pos) = trimmed.find('=') {
+            let key = trimmed[..pos].trim().to_string();
+            let value = trimmed[pos + 1..].trim().to_string();
+            match key.as_str() {
+                "id" => id_override = Some(unquote(&value)),
+                "name" => name = unquote(&value),
+                "description" => description = unquote(&value),
+                "prefix" => prefix = unquote(&value),
+                "extra_data" => {
+                    let array_text = collect_bracketed_value(&value, &mut lines)?;
+                    extra_keys = parse_string_array(&array_text)?
+                        .iter()
+                        .map(|s| parse_key_spec(s))
+                        .collect()
+                }
+                _ => {}
+            }
+        }
+    }
+    flush_section!();
+
+    Ok(registry)
+}
+
 fn php_escape(value: &str) -> String {
     value.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
 /// Спецификация ключа из CIX_PROFILES_DATA: имя и флаг конвертации в байты.
+#[derive(Clone)]
 struct KeySpec {
     /// Имя параметра для поиска в профиле и вывода в PHP
     name: String,
@@ -162,13 +352,14 @@ fn main() {
     // Парсим аргументы командной строки
     let args: Vec<String> = env::args().collect();
     if args.len() < 5 {
-        eprintln!("Usage: {} -c <engines> -o <output_path>", args[0]);
+        eprintln!("Usage: {} -c <engines> -o <output_path> [-e <engines.toml>]", args[0]);
         eprintln!("Example: {} -c \"xen bhyve qemu\" -o out.php", args[0]);
         std::process::exit(1);
     }
 
     let mut capabilities: Option<String> = None;
     let mut output_path: Option<String> = None;
+    let mut engines_file: Option<String> = None;
 
     let mut i = 1;
     while i < args.len() {
@@ -191,6 +382,15 @@ fn main() {
                     std::process::exit(1);
                 }
             }
+            "-e" => {
+                if i + 1 < args.len() {
+                    engines_file = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: -e requires a value");
+                    std::process::exit(1);
+                }
+            }
             _ => {
                 eprintln!("Unknown argument: {}", args[i]);
                 std::process::exit(1);
@@ -198,6 +398,21 @@ fn main() {
         }
     }
 
+    // -e имеет приоритет над CIX_ENGINES, которая в свою очередь переопределяет
+    // встроенный реестр bhyve/xen/qemu.
+    let engines_file = engines_file.or_else(|| env::var("CIX_ENGINES").ok().filter(|s| !s.is_empty()));
+
+    let engine_registry: HashMap<String, EngineDef> = match engines_file {
+        Some(path) => match parse_engines_config(&path) {
+            Ok(registry) => registry,
+            Err(e) => {
+                eprintln!("Cannot read engines file {}: {}", path, e);
+                std::process::exit(1);
+            }
+        },
+        None => default_engine_registry(),
+    };
+
     let capabilities_str = match capabilities {
         Some(s) => s,
         None => {
@@ -229,13 +444,14 @@ fn main() {
     // Создаем список engines динамически на основе указанных в -c
     let mut engines: Vec<EngineData> = Vec::new();
     for engine_id in &engine_ids {
-        match get_engine_metadata(engine_id) {
-            Some((name, description, prefix)) => {
+        match get_engine_metadata(engine_id, &engine_registry) {
+            Some(def) => {
                 engines.push(EngineData {
                     id: engine_id.clone(),
-                    name,
-                    description,
-                    prefix,
+                    name: def.name.clone(),
+                    description: def.description.clone(),
+                    prefix: def.prefix.clone(),
+                    key_specs: def.extra_keys.clone(),
                     profiles: Vec::new(),
                 });
             }
@@ -270,6 +486,20 @@ fn main() {
         std::process::exit(1);
     }
 
+    // Каждый engine получает общие ключи плюс свои собственные extra_data из
+    // реестра. Если extra_data повторяет имя уже заданное в CIX_PROFILES_DATA,
+    // специфичный для engine ключ побеждает вместо дублирования записи.
+    for engine in &mut engines {
+        let mut specs = key_specs.clone();
+        for extra in engine.key_specs.drain(..) {
+            match specs.iter_mut().find(|k| k.name == extra.name) {
+                Some(existing) => *existing = extra,
+                None => specs.push(extra),
+            }
+        }
+        engine.key_specs = specs;
+    }
+
     // Опциональные переменные окружения: VM_CPUS_MAX, VM_CPUS_MIN, VM_RAM_MAX, VM_RAM_MIN, IMGSIZE_MAX, IMGSIZE_MIN
     let vm_cpus_max: Option<String> = env::var("VM_CPUS_MAX").ok().map(|v| v.trim().to_string()).filter(|s| !s.is_empty());
     let vm_cpus_min: Option<String> = env::var("VM_CPUS_MIN").ok().map(|v| v.trim().to_string()).filter(|s| !s.is_empty());
@@ -429,7 +659,7 @@ fn main() {
 
                 // Собираем все найденные параметры профиля
                 let mut profile_params: Vec<(String, String)> = Vec::new();
-                for spec in &key_specs {
+                for spec in &engine.key_specs {
                     if let Some(value) = profile.get(&spec.name) {
                         let out_value = if spec.convert_to_bytes {
                             human_to_bytes(value)